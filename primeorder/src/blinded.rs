@@ -0,0 +1,118 @@
+//! Blinded (masked) field arithmetic for side-channel hardening.
+//!
+//! Wraps a field element generated by [`impl_mont_field_element!`] together
+//! with a multiplicative mask, so that operations whose timing or power
+//! profile is data-dependent (inversion, multiplication) run over a
+//! randomized operand rather than the caller's secret value directly. This
+//! gives ECDSA/ECDH scalar code a drop-in constant-exposure path over the
+//! existing deterministic arithmetic.
+
+/// Implements a [`BlindedElement`](crate::blinded) wrapper (named `$blinded`)
+/// around a field element type `$fe`, along with `invert_blinded` and
+/// `mul_blinded` inherent methods on `$fe` itself.
+///
+/// Everything this macro emits lives behind the crate's `blinding` feature,
+/// matching how the sibling `bits`/`serde` macros self-gate.
+#[macro_export]
+macro_rules! impl_mont_field_element_blinded {
+    ($blinded:ident, $fe:ty) => {
+        #[cfg(feature = "blinding")]
+        impl $fe {
+            /// Samples a random nonzero mask, used to blind an operand
+            /// before an expensive or data-dependent operation.
+            fn random_nonzero_mask<R: $crate::elliptic_curve::rand_core::CryptoRng + $crate::elliptic_curve::rand_core::RngCore>(
+                rng: &mut R,
+            ) -> Self {
+                use $crate::elliptic_curve::ff::Field;
+
+                loop {
+                    let candidate = Self::random(&mut *rng);
+                    if !bool::from(candidate.is_zero()) {
+                        return candidate;
+                    }
+                }
+            }
+
+            /// Computes `self.invert()`, but masks `self` with a random
+            /// nonzero element before the (expensive, Bernstein–Yang)
+            /// inversion runs, and unmasks the result afterward.
+            ///
+            /// `(self·m)⁻¹·m = self⁻¹` for any nonzero mask `m`, so the
+            /// inversion itself never sees the caller's actual operand.
+            /// Goes through [`$blinded`] so the masking step is the same
+            /// one used everywhere else in this module, and so a fresh
+            /// mask is sampled on every call.
+            pub fn invert_blinded<R: $crate::elliptic_curve::rand_core::CryptoRng + $crate::elliptic_curve::rand_core::RngCore>(
+                &self,
+                rng: &mut R,
+            ) -> CtOption<Self> {
+                let blinded = $blinded::new(*self, rng);
+                blinded.masked.invert().map(|masked_inv| masked_inv * blinded.mask)
+            }
+
+            /// Computes `self * rhs`, but masks both operands with a random
+            /// nonzero element (and its inverse) beforehand.
+            ///
+            /// `(self·m)·(rhs·m⁻¹) = self·rhs` for any nonzero mask `m`.
+            pub fn mul_blinded<R: $crate::elliptic_curve::rand_core::CryptoRng + $crate::elliptic_curve::rand_core::RngCore>(
+                &self,
+                rhs: &Self,
+                rng: &mut R,
+            ) -> Self {
+                let blinded = $blinded::new(*self, rng);
+                let mask_inv = blinded
+                    .mask
+                    .invert()
+                    .expect("mask is nonzero by construction");
+                blinded.masked * (*rhs * mask_inv)
+            }
+        }
+
+        /// A field element held in masked form: `masked = value * mask`.
+        ///
+        /// Every blinded operation samples fresh randomness, so repeated
+        /// operations on the same secret don't reuse a mask. Call
+        /// [`to_canonical`](Self::to_canonical) to unmask and obtain the
+        /// plain value when it's actually needed.
+        #[cfg(feature = "blinding")]
+        #[derive(Copy, Clone)]
+        pub struct $blinded {
+            masked: $fe,
+            mask: $fe,
+        }
+
+        #[cfg(feature = "blinding")]
+        impl $blinded {
+            /// Masks `value` with a freshly sampled nonzero random element.
+            pub fn new<R: $crate::elliptic_curve::rand_core::CryptoRng + $crate::elliptic_curve::rand_core::RngCore>(
+                value: $fe,
+                rng: &mut R,
+            ) -> Self {
+                let mask = <$fe>::random_nonzero_mask(rng);
+                Self {
+                    masked: value * mask,
+                    mask,
+                }
+            }
+
+            /// Re-masks this element with a freshly sampled nonzero random
+            /// element, so a subsequent operation doesn't reuse the
+            /// previous call's randomness.
+            pub fn reblind<R: $crate::elliptic_curve::rand_core::CryptoRng + $crate::elliptic_curve::rand_core::RngCore>(
+                &mut self,
+                rng: &mut R,
+            ) {
+                *self = Self::new(self.to_canonical(), rng);
+            }
+
+            /// Unmasks this element, returning the plain field element.
+            pub fn to_canonical(&self) -> $fe {
+                self.masked
+                    * self
+                        .mask
+                        .invert()
+                        .expect("mask is nonzero by construction")
+            }
+        }
+    };
+}
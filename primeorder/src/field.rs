@@ -468,6 +468,319 @@ macro_rules! impl_mont_field_element_arithmetic {
                 iter.copied().product()
             }
         }
+
+        #[cfg(feature = "alloc")]
+        impl $fe {
+            /// Inverts a batch of field elements in place, performing only a
+            /// single modular inversion for the whole batch via Montgomery's
+            /// trick (<https://iacr.org/archive/crypto2001/21390389.pdf>, §5).
+            ///
+            /// Returns a [`Choice`] which is falsy if any element of
+            /// `inputs` was zero; the corresponding outputs for zero inputs
+            /// are left unchanged.
+            pub fn batch_invert(inputs: &mut [Self]) -> Choice {
+                let mut scratch = alloc::vec![Self::ONE; inputs.len()];
+                let mut acc = Self::ONE;
+                let mut all_nonzero = Choice::from(1);
+
+                for (scratch_i, input) in scratch.iter_mut().zip(inputs.iter()) {
+                    *scratch_i = acc;
+                    let is_nonzero = !input.is_zero();
+                    all_nonzero &= is_nonzero;
+                    acc = Self::conditional_select(&acc, &(acc * input), is_nonzero);
+                }
+
+                let mut acc_inv = acc.invert().unwrap_or(Self::ONE);
+
+                for (input, scratch_i) in inputs.iter_mut().zip(scratch.iter()).rev() {
+                    let original = *input;
+                    let is_nonzero = !original.is_zero();
+                    let inverted = acc_inv * scratch_i;
+                    *input = Self::conditional_select(&original, &inverted, is_nonzero);
+                    acc_inv = Self::conditional_select(&acc_inv, &(acc_inv * &original), is_nonzero);
+                }
+
+                all_nonzero
+            }
+
+            /// Inverts every element of `inputs`, returning the results as a
+            /// new allocation without modifying `inputs`.
+            ///
+            /// The returned [`CtOption`] is none if any element of `inputs`
+            /// was zero.
+            pub fn batch_invert_vec(inputs: &[Self]) -> CtOption<alloc::vec::Vec<Self>> {
+                let mut outputs = inputs.to_vec();
+                let all_nonzero = Self::batch_invert(&mut outputs);
+                CtOption::new(outputs, all_nonzero)
+            }
+        }
+    };
+}
+
+/// Implements a `sqrt` inherent method for a field element type generated by
+/// [`impl_mont_field_element!`], using the [`ff::PrimeField`] constants
+/// (`S`, `ROOT_OF_UNITY`) the crate already requires of every prime field.
+///
+/// # Forms
+///
+/// `impl_mont_field_sqrt!($fe, $t_minus_1_over_2)` generates a general
+/// Tonelli–Shanks square root (variable-time in the exponent search, like
+/// `pow_vartime`), where `$t_minus_1_over_2` is `(t − 1) / 2` as a
+/// little-endian `u64` exponent array suitable for `pow_vartime`, and `t` is
+/// the odd cofactor such that `p − 1 = t·2^S`.
+///
+/// `impl_mont_field_sqrt!($fe, p3mod4, $p_plus_1_over_4)` generates the
+/// fast-path square root for primes `p ≡ 3 (mod 4)`, where
+/// `$p_plus_1_over_4` is `(p + 1) / 4` as a little-endian `u64` exponent
+/// array.
+#[macro_export]
+macro_rules! impl_mont_field_sqrt {
+    ($fe:tt, $t_minus_1_over_2:expr) => {
+        impl $fe {
+            /// Returns the square root of this element, if it exists.
+            pub fn sqrt(&self) -> CtOption<Self> {
+                // Tonelli–Shanks, following the standard presentation for
+                // primes p ≡ 1 (mod 4): https://eprint.iacr.org/2012/685.pdf
+                // (page 12, algorithm 5).
+                //
+                // Zero has to be special-cased: b below would be zero too,
+                // so the inner search for `b^(2^m) == 1` would never
+                // succeed and zero would be reported as a non-residue.
+                if bool::from(self.is_zero()) {
+                    return CtOption::new(Self::ZERO, Choice::from(1));
+                }
+
+                // w = self^((t - 1) / 2)
+                let w = self.pow_vartime(&$t_minus_1_over_2);
+                let mut x = *self * &w;
+                let mut b = x * &w;
+                let mut v = Self::S;
+                let mut z = Self::ROOT_OF_UNITY;
+
+                while bool::from(!b.ct_eq(&Self::ONE)) {
+                    // Find the least m such that b^(2^m) == 1. By Fermat's
+                    // little theorem b^(2^v) == 1 always holds (for any
+                    // nonzero `self`), so this search always terminates
+                    // with m <= v; m == v exactly is only possible when
+                    // `self` is a non-residue, since a genuine square root
+                    // maintains the invariant that b's order strictly
+                    // divides 2^(v-1).
+                    let mut m = 0u32;
+                    let mut b2m = b;
+                    while m < v && bool::from(!b2m.ct_eq(&Self::ONE)) {
+                        b2m = b2m.square();
+                        m += 1;
+                    }
+
+                    if m == v {
+                        return CtOption::new(Self::ZERO, Choice::from(0));
+                    }
+
+                    // g = z^(2^(v - m - 1)), by repeated squaring rather
+                    // than shifting `1u64` by the exponent: `v - m - 1` can
+                    // reach or exceed 64 for a field with large 2-adicity
+                    // `S`, which would overflow/panic a `1u64 << _` shift.
+                    let mut g = z;
+                    for _ in 0..(v - m - 1) {
+                        g = g.square();
+                    }
+                    z = g.square();
+                    x *= &g;
+                    b *= &z;
+                    v = m;
+                }
+
+                CtOption::new(x, x.square().ct_eq(self))
+            }
+        }
+    };
+
+    ($fe:tt, p3mod4, $p_plus_1_over_4:expr) => {
+        impl $fe {
+            /// Returns the square root of this element, if it exists.
+            ///
+            /// Uses the fast path available for primes `p ≡ 3 (mod 4)`:
+            /// `self^((p + 1) / 4)`.
+            pub fn sqrt(&self) -> CtOption<Self> {
+                let x = self.pow_vartime(&$p_plus_1_over_4);
+                CtOption::new(x, x.square().ct_eq(self))
+            }
+        }
+    };
+}
+
+/// Implements a wide-reduction `from_uniform_bytes` constructor for a field
+/// element type generated by [`impl_mont_field_element!`], mapping an
+/// oversized, uniformly random byte string onto a near-uniform field
+/// element as required by RFC 9380 hash-to-curve and VRF constructions.
+///
+/// `$modulus` is the field's prime modulus (as used by
+/// [`impl_mont_field_element!`]); `$r2`/`$r3` are `R^2 mod p`/`R^3 mod p`
+/// (`$uint`s, little-endian words), alongside the `R^2 mod p` constant the
+/// crate's Montgomery conversion already requires. Callers must size the
+/// input `N` to at least `size_of::<$uint>() + 16` bytes (so the bias
+/// introduced by the reduction is below `2^-128`) and at most
+/// `2 * size_of::<$uint>()` bytes (so the high half fits back into
+/// `$uint`).
+#[macro_export]
+macro_rules! impl_mont_field_element_wide_reduction {
+    (
+        $fe:tt,
+        $uint:ty,
+        $modulus:expr,
+        $mul:ident,
+        $add:ident,
+        $r2:expr,
+        $r3:expr
+    ) => {
+        impl $fe {
+            /// Maps a wide, uniformly random byte string onto a near-uniform
+            /// element of this field.
+            ///
+            /// `bytes` is interpreted as a big-endian double-width integer,
+            /// split into a low half the width of [`
+            #[doc = stringify!($uint)]
+            /// `] and a (possibly narrower, zero-extended) high half, then
+            /// reduced into Montgomery form as
+            /// `lo·R + hi·2^k·R = lo·(R mod p) + hi·(R² mod p)`, using a
+            /// single Montgomery multiplication per half.
+            pub fn from_uniform_bytes<const N: usize>(bytes: &[u8; N]) -> Self {
+                const UINT_BYTES: usize = ::core::mem::size_of::<$uint>();
+                debug_assert!(N >= UINT_BYTES + 16);
+                debug_assert!(N <= 2 * UINT_BYTES);
+
+                let (hi_bytes, lo_bytes) = bytes.split_at(N - UINT_BYTES);
+
+                // `lo`/`hi` are each uniform over the full `$uint` range,
+                // which can exceed the modulus, so both must be reduced
+                // mod p before they're fed to the Montgomery multiply below
+                // (whose `< p` precondition `from_uint_unchecked` callers
+                // are normally responsible for upholding).
+                let modulus = $crate::elliptic_curve::bigint::NonZero::new($modulus).unwrap();
+
+                let lo = <$uint>::from_be_slice(lo_bytes).rem_vartime(&modulus);
+
+                let mut hi_buf = [0u8; UINT_BYTES];
+                hi_buf[UINT_BYTES - hi_bytes.len()..].copy_from_slice(hi_bytes);
+                let hi = <$uint>::from_be_slice(&hi_buf).rem_vartime(&modulus);
+
+                let lo_mont = $mul(lo.as_words(), $r2.as_words());
+                let hi_mont = $mul(hi.as_words(), $r3.as_words());
+
+                Self(<$uint>::from_words($add(&lo_mont, &hi_mont)))
+            }
+        }
+
+        impl<const N: usize> $crate::elliptic_curve::ff::FromUniformBytes<N> for $fe {
+            fn from_uniform_bytes(bytes: &[u8; N]) -> Self {
+                Self::from_uniform_bytes(bytes)
+            }
+        }
+    };
+}
+
+/// Implement `from_uniform_bytes` tests, for an input width of `$n` bytes.
+#[macro_export]
+macro_rules! impl_field_from_uniform_bytes_tests {
+    ($fe:tt, $n:expr) => {
+        #[test]
+        fn from_uniform_bytes_all_zero_is_zero() {
+            let bytes = [0u8; $n];
+            assert_eq!($fe::from_uniform_bytes(&bytes), $fe::ZERO);
+        }
+
+        #[test]
+        fn from_uniform_bytes_low_half_only_matches_from_u64() {
+            // With every byte but the trailing one zero, the "high" half
+            // contributes nothing, so the result is exactly the low byte
+            // interpreted as an integer.
+            let mut bytes = [0u8; $n];
+            *bytes.last_mut().unwrap() = 7;
+            assert_eq!($fe::from_uniform_bytes(&bytes), $fe::from(7u64));
+        }
+
+        #[test]
+        fn from_uniform_bytes_is_deterministic() {
+            let mut bytes = [0u8; $n];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = i as u8;
+            }
+            assert_eq!(
+                $fe::from_uniform_bytes(&bytes),
+                $fe::from_uniform_bytes(&bytes)
+            );
+        }
+    };
+}
+
+/// Implements [`ff::PrimeFieldBits`] for a field element type generated by
+/// [`impl_mont_field_element!`], behind the crate's `bits` feature.
+///
+/// Exposes `to_le_bits` (the element's canonical little-endian bit
+/// representation) and `char_le_bits` (the modulus's bits), unblocking
+/// constant-time variable-base scalar multiplication and windowed-NAF
+/// consumers that would otherwise have to re-derive bits from bytes.
+///
+/// `$limbs` is the number of words in `$uint`, i.e. its `LIMBS` constant.
+#[macro_export]
+macro_rules! impl_mont_field_element_bits {
+    ($fe:tt, $uint:ty, $modulus:expr, $limbs:expr) => {
+        #[cfg(feature = "bits")]
+        impl $crate::elliptic_curve::ff::PrimeFieldBits for $fe {
+            type ReprBits = [$crate::elliptic_curve::bigint::Word; $limbs];
+
+            fn to_le_bits(&self) -> $crate::elliptic_curve::ff::FieldBits<Self::ReprBits> {
+                self.to_canonical().to_words().into()
+            }
+
+            fn char_le_bits() -> $crate::elliptic_curve::ff::FieldBits<Self::ReprBits> {
+                $modulus.to_words().into()
+            }
+        }
+    };
+}
+
+/// Implements `serde`'s `Serialize`/`Deserialize` for a field element type
+/// generated by [`impl_mont_field_element!`], behind the crate's `serde`
+/// feature.
+///
+/// Serializes as the canonical big-endian fixed-width encoding from
+/// `to_bytes()` — lowercase hex for human-readable formats (JSON, etc.),
+/// raw bytes otherwise (CBOR, bincode, etc.) — via `serdect`. Deserializing
+/// goes back through `from_bytes`, so a non-canonical (`>= p`) encoding is
+/// rejected as a `serde::de::Error` rather than silently reduced.
+#[macro_export]
+macro_rules! impl_mont_field_element_serde {
+    ($fe:tt, $bytes:ty) => {
+        #[cfg(feature = "serde")]
+        impl $crate::elliptic_curve::serde::Serialize for $fe {
+            fn serialize<S: $crate::elliptic_curve::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::elliptic_curve::serdect::array::serialize_hex_lower_or_bin(
+                    &self.to_bytes(),
+                    serializer,
+                )
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> $crate::elliptic_curve::serde::Deserialize<'de> for $fe {
+            fn deserialize<D: $crate::elliptic_curve::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                use $crate::elliptic_curve::serde::de::Error as _;
+
+                let mut bytes = <$bytes>::default();
+                $crate::elliptic_curve::serdect::array::deserialize_hex_or_bin(
+                    &mut bytes,
+                    deserializer,
+                )?;
+                Option::from(Self::from_bytes(&bytes))
+                    .ok_or_else(|| D::Error::custom("non-canonical field element encoding"))
+            }
+        }
     };
 }
 
@@ -603,6 +916,45 @@ macro_rules! impl_field_invert_tests {
     };
 }
 
+/// Implement `batch_invert` tests.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! impl_field_batch_invert_tests {
+    ($fe:tt) => {
+        #[test]
+        fn batch_invert() {
+            let one = $fe::ONE;
+            let three = one + &one + &one;
+            let five = three + &one + &one;
+
+            let mut values = alloc::vec![one, three, five];
+            let all_nonzero = $fe::batch_invert(&mut values);
+
+            assert!(bool::from(all_nonzero));
+            assert_eq!(values[0], one.invert().unwrap());
+            assert_eq!(values[1], three.invert().unwrap());
+            assert_eq!(values[2], five.invert().unwrap());
+        }
+
+        #[test]
+        fn batch_invert_with_embedded_zero() {
+            let one = $fe::ONE;
+            let three = one + &one + &one;
+
+            let mut values = alloc::vec![one, $fe::ZERO, three];
+            let original = values.clone();
+            let all_nonzero = $fe::batch_invert(&mut values);
+
+            assert!(!bool::from(all_nonzero));
+            // The zero element is left untouched; the rest still invert
+            // correctly despite it.
+            assert_eq!(values[1], original[1]);
+            assert_eq!(values[0], original[0].invert().unwrap());
+            assert_eq!(values[2], original[2].invert().unwrap());
+        }
+    };
+}
+
 /// Implement field element square root tests.
 #[macro_export]
 macro_rules! impl_field_sqrt_tests {
@@ -615,6 +967,22 @@ macro_rules! impl_field_sqrt_tests {
                 assert_eq!(sqrt.square(), fe);
             }
         }
+
+        #[test]
+        fn sqrt_of_zero() {
+            let sqrt = $fe::ZERO.sqrt().unwrap();
+            assert_eq!(sqrt, $fe::ZERO);
+        }
+    };
+
+    ($fe:tt, non_residue = $non_residue:expr) => {
+        $crate::impl_field_sqrt_tests!($fe);
+
+        #[test]
+        fn sqrt_of_non_residue_is_none() {
+            let non_residue = $fe::from($non_residue);
+            assert!(bool::from(non_residue.sqrt().is_none()));
+        }
     };
 }
 
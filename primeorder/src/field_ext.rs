@@ -0,0 +1,1200 @@
+//! Macros for building the quadratic/cubic extension-field towers
+//! (`Fp2`/`Fp6`/`Fp12`) used by pairing-friendly curves (e.g. BN254,
+//! BLS12-381) on top of a base field `$fe` generated by
+//! [`impl_mont_field_element!`](crate::impl_mont_field_element).
+//!
+//! Unlike the prime-field macros, these operate purely in terms of the base
+//! field's `Field` arithmetic (`+`, `-`, `*`, `square`, `invert`) rather than
+//! raw Montgomery limb functions, since an extension field has no
+//! Montgomery representation of its own.
+
+/// Implements the reference-taking `Add`/`Sub`/`Mul` variants, the
+/// `*Assign` impls, and `Sum`/`Product` over `&Self`, for an extension-field
+/// type `$t` whose owned-operand `Add`/`Sub`/`Mul` are already implemented.
+///
+/// Mirrors the role [`impl_field_op!`](crate::impl_field_op) plays for the
+/// base-field macro — [`ff::Field`](crate::elliptic_curve::ff::Field)'s
+/// supertrait bounds require this full op set, not just the owned-operand
+/// forms.
+#[macro_export]
+macro_rules! impl_field_ext_ops {
+    ($t:ty) => {
+        impl ::core::ops::Add<&$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn add(self, rhs: &$t) -> $t {
+                self + *rhs
+            }
+        }
+
+        impl ::core::ops::Add<&$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn add(self, rhs: &$t) -> $t {
+                *self + *rhs
+            }
+        }
+
+        impl ::core::ops::Sub<&$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn sub(self, rhs: &$t) -> $t {
+                self - *rhs
+            }
+        }
+
+        impl ::core::ops::Sub<&$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn sub(self, rhs: &$t) -> $t {
+                *self - *rhs
+            }
+        }
+
+        impl ::core::ops::Mul<&$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn mul(self, rhs: &$t) -> $t {
+                self * *rhs
+            }
+        }
+
+        impl ::core::ops::Mul<&$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn mul(self, rhs: &$t) -> $t {
+                *self * *rhs
+            }
+        }
+
+        impl ::core::ops::AddAssign<$t> for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: $t) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl ::core::ops::AddAssign<&$t> for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: &$t) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl ::core::ops::SubAssign<$t> for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $t) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl ::core::ops::SubAssign<&$t> for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: &$t) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl ::core::ops::MulAssign<$t> for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl ::core::ops::MulAssign<&$t> for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: &$t) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $t> for $t {
+            fn sum<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                iter.copied().sum()
+            }
+        }
+
+        impl<'a> ::core::iter::Product<&'a $t> for $t {
+            fn product<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+/// Implements `$fp2 = $fe[u] / (u^2 - beta)`, the quadratic extension of a
+/// base field `$fe` by a non-residue `beta`.
+///
+/// `$mul_by_nonresidue` is a function or closure expression, called with a
+/// single argument `x: $fe`, computing `x * beta`; for the common choice
+/// `beta = -1` this is simply `|x| -x`.
+///
+/// This also implements [`ff::Field`](crate::elliptic_curve::ff::Field) for
+/// `$fp2`, whose `sqrt` delegates to an inherent `sqrt` method — pair this
+/// macro with [`impl_field_ext2_sqrt!`] to provide it.
+#[macro_export]
+macro_rules! impl_field_ext2 {
+    ($fp2:ident, $fe:ty, $mul_by_nonresidue:expr) => {
+        /// An element of the quadratic extension field
+        #[doc = concat!("`", stringify!($fp2), " = ", stringify!($fe), "[u] / (u^2 - beta)`.")]
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $fp2 {
+            /// Coefficient of `1`.
+            pub c0: $fe,
+            /// Coefficient of `u`.
+            pub c1: $fe,
+        }
+
+        impl $fp2 {
+            /// Zero element.
+            pub const ZERO: Self = Self {
+                c0: <$fe>::ZERO,
+                c1: <$fe>::ZERO,
+            };
+
+            /// Multiplicative identity.
+            pub const ONE: Self = Self {
+                c0: <$fe>::ONE,
+                c1: <$fe>::ZERO,
+            };
+
+            /// Multiply `self` by the quadratic non-residue `beta`.
+            #[must_use]
+            pub fn mul_by_nonresidue(&self) -> Self {
+                fn nonresidue(x: $fe) -> $fe {
+                    $mul_by_nonresidue(x)
+                }
+
+                Self {
+                    c0: nonresidue(self.c0),
+                    c1: nonresidue(self.c1),
+                }
+            }
+
+            /// The Frobenius endomorphism restricted to `$fp2`, i.e.
+            /// conjugation: `(a0 + a1*u)^p = a0 - a1*u`.
+            #[must_use]
+            pub fn frobenius_map(&self) -> Self {
+                self.conjugate()
+            }
+
+            /// Complex conjugate: `a0 + a1*u -> a0 - a1*u`.
+            #[must_use]
+            pub fn conjugate(&self) -> Self {
+                Self {
+                    c0: self.c0,
+                    c1: -self.c1,
+                }
+            }
+
+            /// Multiply a bare base-field element by `beta`, the same
+            /// non-residue used by [`Self::mul_by_nonresidue`].
+            fn nonresidue_fe(x: $fe) -> $fe {
+                $mul_by_nonresidue(x)
+            }
+
+            /// Compute modular square: `a0^2 + beta*a1^2 + 2*a0*a1*u`.
+            #[must_use]
+            pub fn square(&self) -> Self {
+                let a0a1 = self.c0 * self.c1;
+                let beta_a1_sq = Self::nonresidue_fe(self.c1 * self.c1);
+
+                Self {
+                    c0: self.c0 * self.c0 + beta_a1_sq,
+                    c1: a0a1.double(),
+                }
+            }
+
+            /// Compute the field norm `a0^2 - beta*a1^2`, which lies in the
+            /// base field `$fe` and is used by [`Self::invert`].
+            pub fn norm(&self) -> $fe {
+                self.c0 * self.c0 - Self::nonresidue_fe(self.c1 * self.c1)
+            }
+
+            /// Returns the multiplicative inverse of this element, if it is
+            /// nonzero: `(a0 - a1*u) / norm(a)`.
+            pub fn invert(&self) -> CtOption<Self> {
+                self.norm().invert().map(|norm_inv| Self {
+                    c0: self.c0 * norm_inv,
+                    c1: -self.c1 * norm_inv,
+                })
+            }
+
+            /// Returns `self^exp`, where `exp` is a little-endian integer
+            /// exponent, mirroring `$fe::pow_vartime`.
+            ///
+            /// **This operation is variable time with respect to the
+            /// exponent.**
+            pub fn pow_vartime(&self, exp: &[u64]) -> Self {
+                let mut res = Self::ONE;
+
+                for e in exp.iter().rev() {
+                    for i in (0..64).rev() {
+                        res = res.square();
+
+                        if ((e >> i) & 1) == 1 {
+                            res = res * self;
+                        }
+                    }
+                }
+
+                res
+            }
+        }
+
+        impl From<$fe> for $fp2 {
+            fn from(c0: $fe) -> Self {
+                Self { c0, c1: <$fe>::ZERO }
+            }
+        }
+
+        impl ConditionallySelectable for $fp2 {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self {
+                    c0: <$fe>::conditional_select(&a.c0, &b.c0, choice),
+                    c1: <$fe>::conditional_select(&a.c1, &b.c1, choice),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $fp2 {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+            }
+        }
+
+        impl Eq for $fp2 {}
+        impl PartialEq for $fp2 {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
+        impl $crate::elliptic_curve::ff::Field for $fp2 {
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+
+            fn try_from_rng<R: $crate::elliptic_curve::rand_core::TryRngCore + ?Sized>(
+                rng: &mut R,
+            ) -> core::result::Result<Self, R::Error> {
+                use $crate::elliptic_curve::ff::Field;
+
+                Ok(Self {
+                    c0: <$fe>::try_from_rng(rng)?,
+                    c1: <$fe>::try_from_rng(rng)?,
+                })
+            }
+
+            fn is_zero(&self) -> Choice {
+                Self::ZERO.ct_eq(self)
+            }
+
+            #[must_use]
+            fn square(&self) -> Self {
+                self.square()
+            }
+
+            #[must_use]
+            fn double(&self) -> Self {
+                *self + *self
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                self.invert()
+            }
+
+            fn sqrt(&self) -> CtOption<Self> {
+                self.sqrt()
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                // `$fp2` has no Tonelli–Shanks tower constants of its own
+                // (it isn't a `PrimeField`), so fall back to the division
+                // this method exists to avoid; `sqrt` itself still uses the
+                // dedicated algorithm from `impl_field_ext2_sqrt!`.
+                let inv = div.invert();
+                let ratio = *num * inv.unwrap_or(Self::ZERO);
+                let sqrt = ratio.sqrt();
+                let is_square = inv.is_some() & sqrt.is_some();
+                (
+                    is_square,
+                    Self::conditional_select(&Self::ZERO, &sqrt.unwrap_or(Self::ZERO), is_square),
+                )
+            }
+        }
+
+        impl ::core::ops::Add for $fp2 {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    c0: self.c0 + rhs.c0,
+                    c1: self.c1 + rhs.c1,
+                }
+            }
+        }
+
+        impl ::core::ops::Sub for $fp2 {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    c0: self.c0 - rhs.c0,
+                    c1: self.c1 - rhs.c1,
+                }
+            }
+        }
+
+        impl ::core::ops::Neg for $fp2 {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    c0: -self.c0,
+                    c1: -self.c1,
+                }
+            }
+        }
+
+        impl ::core::ops::Mul for $fp2 {
+            type Output = Self;
+
+            /// Karatsuba multiplication, saving one base-field multiply:
+            /// `(a0+a1 u)(b0+b1 u) = (a0 b0 + beta a1 b1) + (a0 b1 + a1 b0) u`.
+            fn mul(self, rhs: Self) -> Self {
+                let a0b0 = self.c0 * rhs.c0;
+                let a1b1 = self.c1 * rhs.c1;
+                let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - a0b0 - a1b1;
+
+                Self {
+                    c0: a0b0 + Self::nonresidue_fe(a1b1),
+                    c1,
+                }
+            }
+        }
+
+        impl ::core::iter::Sum for $fp2 {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, ::core::ops::Add::add)
+            }
+        }
+
+        impl ::core::iter::Product for $fp2 {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, ::core::ops::Mul::mul)
+            }
+        }
+
+        $crate::impl_field_ext_ops!($fp2);
+    };
+}
+
+/// Implement tests for an `$fp2` type generated by [`impl_field_ext2!`].
+#[macro_export]
+macro_rules! impl_field_ext2_tests {
+    ($fp2:tt) => {
+        #[test]
+        fn fp2_invert_round_trips() {
+            let one = $fp2::ONE.c0;
+            let two = one + one;
+            let three = two + one;
+            let elem = $fp2 { c0: three, c1: two };
+
+            let inv = elem.invert().unwrap();
+            assert_eq!(elem * inv, $fp2::ONE);
+        }
+
+        #[test]
+        fn fp2_invert_of_zero_is_none() {
+            assert!(bool::from($fp2::ZERO.invert().is_none()));
+        }
+
+        #[test]
+        fn fp2_square_matches_self_mul() {
+            let one = $fp2::ONE.c0;
+            let two = one + one;
+            let elem = $fp2 { c0: two, c1: one };
+            assert_eq!(elem.square(), elem * elem);
+        }
+
+        #[test]
+        fn fp2_conjugate_is_involution() {
+            let one = $fp2::ONE.c0;
+            let elem = $fp2 { c0: one, c1: one + one };
+            assert_eq!(elem.conjugate().conjugate(), elem);
+        }
+
+        #[test]
+        fn fp2_sum_and_product() {
+            let one = $fp2::ONE;
+            let two = one + one;
+
+            let sum: $fp2 = [one, two].into_iter().sum();
+            assert_eq!(sum, one + two);
+
+            let product: $fp2 = [one, two].into_iter().product();
+            assert_eq!(product, one * two);
+        }
+    };
+}
+
+/// Implements a `sqrt` inherent method for an `$fp2` type generated by
+/// [`impl_field_ext2!`], for the common case of a base field `$fe` whose
+/// prime modulus `p` satisfies `p ≡ 3 (mod 4)`.
+///
+/// Follows Algorithm 9 of <https://eprint.iacr.org/2012/685.pdf>. Works for
+/// any non-residue `beta` (not just `beta = -1`): the "twisted" branch
+/// multiplies by a square root of `-1` derived from `beta`, which is `u`
+/// itself only when `beta = -1`, rather than hardcoding `u^2 = -1`.
+///
+/// `$p_minus_3_over_4` and `$p_minus_1_over_2` are `(p − 3) / 4` and
+/// `(p − 1) / 2` respectively, as little-endian `u64` exponent arrays
+/// suitable for `$fp2::pow_vartime`.
+#[macro_export]
+macro_rules! impl_field_ext2_sqrt {
+    ($fp2:ty, $p_minus_3_over_4:expr, $p_minus_1_over_2:expr) => {
+        impl $fp2 {
+            /// Returns the square root of this element, if it exists.
+            pub fn sqrt(&self) -> CtOption<Self> {
+                let is_zero = self.ct_eq(&Self::ZERO);
+
+                // a1 = self^((p - 3) / 4)
+                let a1 = self.pow_vartime(&$p_minus_3_over_4);
+                let alpha = a1.square() * self;
+                let a0 = alpha.frobenius_map() * alpha;
+
+                let neg_one = -Self::ONE;
+                let x0 = a1 * self;
+
+                // If alpha == -1, self has no sqrt in the subfield fast path
+                // and the "twisted" branch below is used instead; if a0 ==
+                // -1, self is a non-residue.
+                let is_alpha_neg_one = alpha.ct_eq(&neg_one);
+
+                // twisted = x0 * sqrt(-1), where sqrt(-1) = s*u with
+                // s^2 = k = -1/beta: k is a square in the base field, as
+                // -1 and beta are both non-squares there (p ≡ 3 (mod 4)),
+                // so s = k^((p + 1) / 4). For beta = -1, s = 1.
+                let k = Self::from(-Self::nonresidue_fe(Self::ONE.c0))
+                    .invert()
+                    .unwrap_or(Self::ZERO);
+                let s = k.pow_vartime(&$p_minus_3_over_4) * k;
+                let twisted = x0 * Self {
+                    c0: Self::ZERO.c0,
+                    c1: s.c0,
+                };
+                let generic = x0 * (alpha + Self::ONE).pow_vartime(&$p_minus_1_over_2);
+
+                let result = Self::conditional_select(&generic, &twisted, is_alpha_neg_one);
+                let result = Self::conditional_select(&result, &Self::ZERO, is_zero);
+
+                CtOption::new(result, !(a0.ct_eq(&neg_one) & !is_zero))
+            }
+        }
+    };
+}
+
+/// Implement tests for the `sqrt` inherent method added by
+/// [`impl_field_ext2_sqrt!`].
+#[macro_export]
+macro_rules! impl_field_ext2_sqrt_tests {
+    ($fp2:tt) => {
+        #[test]
+        fn fp2_sqrt_round_trips() {
+            let one = $fp2::ONE.c0;
+            let two = one + one;
+            let elem = $fp2 { c0: two, c1: one };
+            let square = elem.square();
+
+            let sqrt = square.sqrt().unwrap();
+            assert_eq!(sqrt.square(), square);
+        }
+
+        #[test]
+        fn fp2_sqrt_of_zero() {
+            let sqrt = $fp2::ZERO.sqrt().unwrap();
+            assert_eq!(sqrt, $fp2::ZERO);
+        }
+    };
+}
+
+/// Implements `$fp6 = $fp2[v] / (v^3 - xi)`, the cubic extension of a
+/// quadratic extension field `$fp2` (built with [`impl_field_ext2!`]) by a
+/// non-residue `xi`.
+///
+/// `$mul_by_nonresidue` is a function or closure expression, called with a
+/// single argument `x: $fp2`, computing `x * xi`.
+#[macro_export]
+macro_rules! impl_field_ext6 {
+    ($fp6:ident, $fp2:ty, $mul_by_nonresidue:expr) => {
+        /// An element of the cubic extension field
+        #[doc = concat!("`", stringify!($fp6), " = ", stringify!($fp2), "[v] / (v^3 - xi)`.")]
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $fp6 {
+            /// Coefficient of `1`.
+            pub c0: $fp2,
+            /// Coefficient of `v`.
+            pub c1: $fp2,
+            /// Coefficient of `v^2`.
+            pub c2: $fp2,
+        }
+
+        impl $fp6 {
+            /// Zero element.
+            pub const ZERO: Self = Self {
+                c0: <$fp2>::ZERO,
+                c1: <$fp2>::ZERO,
+                c2: <$fp2>::ZERO,
+            };
+
+            /// Multiplicative identity.
+            pub const ONE: Self = Self {
+                c0: <$fp2>::ONE,
+                c1: <$fp2>::ZERO,
+                c2: <$fp2>::ZERO,
+            };
+
+            /// Multiply `self` by the cubic non-residue `xi`.
+            #[must_use]
+            pub fn mul_by_nonresidue(&self) -> Self {
+                fn nonresidue(x: $fp2) -> $fp2 {
+                    $mul_by_nonresidue(x)
+                }
+
+                // (c0 + c1 v + c2 v^2) * v = c2*xi + c0*v + c1*v^2
+                Self {
+                    c0: nonresidue(self.c2),
+                    c1: self.c0,
+                    c2: self.c1,
+                }
+            }
+
+            /// Returns the multiplicative inverse of this element, if it is
+            /// nonzero, via the degree-3 extension inversion formula.
+            pub fn invert(&self) -> CtOption<Self> {
+                let c0 = self.c0.square() - (self.c1 * self.c2).mul_by_nonresidue_outer();
+                let c1 = self.c2.square().mul_by_nonresidue_outer() - self.c0 * self.c1;
+                let c2 = self.c1.square() - self.c0 * self.c2;
+
+                let t = ((self.c1 * c2) + (self.c2 * c1)).mul_by_nonresidue_outer() + self.c0 * c0;
+
+                t.invert().map(|t_inv| Self {
+                    c0: c0 * t_inv,
+                    c1: c1 * t_inv,
+                    c2: c2 * t_inv,
+                })
+            }
+        }
+
+        impl From<$fp2> for $fp6 {
+            fn from(c0: $fp2) -> Self {
+                Self {
+                    c0,
+                    c1: <$fp2>::ZERO,
+                    c2: <$fp2>::ZERO,
+                }
+            }
+        }
+
+        impl ConditionallySelectable for $fp6 {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self {
+                    c0: <$fp2>::conditional_select(&a.c0, &b.c0, choice),
+                    c1: <$fp2>::conditional_select(&a.c1, &b.c1, choice),
+                    c2: <$fp2>::conditional_select(&a.c2, &b.c2, choice),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $fp6 {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+            }
+        }
+
+        impl Eq for $fp6 {}
+        impl PartialEq for $fp6 {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
+        impl $crate::elliptic_curve::ff::Field for $fp6 {
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+
+            fn try_from_rng<R: $crate::elliptic_curve::rand_core::TryRngCore + ?Sized>(
+                rng: &mut R,
+            ) -> core::result::Result<Self, R::Error> {
+                use $crate::elliptic_curve::ff::Field;
+
+                Ok(Self {
+                    c0: <$fp2>::try_from_rng(rng)?,
+                    c1: <$fp2>::try_from_rng(rng)?,
+                    c2: <$fp2>::try_from_rng(rng)?,
+                })
+            }
+
+            fn is_zero(&self) -> Choice {
+                Self::ZERO.ct_eq(self)
+            }
+
+            #[must_use]
+            fn square(&self) -> Self {
+                *self * *self
+            }
+
+            #[must_use]
+            fn double(&self) -> Self {
+                *self + *self
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                self.invert()
+            }
+
+            fn sqrt(&self) -> CtOption<Self> {
+                // No general square-root algorithm is implemented for
+                // `$fp6`; it isn't used as a base field for hash-to-curve
+                // or other contexts that need one. Zero is still its own
+                // (trivial) square root, but calling this on a nonzero
+                // value is a bug in the caller, not a legitimate "not a
+                // square" answer — catch that loudly in debug rather than
+                // silently reporting every nonzero input as a non-square.
+                let is_zero = self.ct_eq(&Self::ZERO);
+                debug_assert!(
+                    bool::from(is_zero),
+                    "{}::sqrt has no implementation for nonzero inputs",
+                    stringify!($fp6),
+                );
+                CtOption::new(Self::ZERO, is_zero)
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                let is_num_zero = num.ct_eq(&Self::ZERO);
+                let is_div_zero = div.ct_eq(&Self::ZERO);
+                debug_assert!(
+                    bool::from(is_num_zero) || bool::from(is_div_zero),
+                    "{}::sqrt_ratio has no implementation for nonzero numerators",
+                    stringify!($fp6),
+                );
+                (is_num_zero & !is_div_zero, Self::ZERO)
+            }
+        }
+
+        impl ::core::ops::Add for $fp6 {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    c0: self.c0 + rhs.c0,
+                    c1: self.c1 + rhs.c1,
+                    c2: self.c2 + rhs.c2,
+                }
+            }
+        }
+
+        impl ::core::ops::Sub for $fp6 {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    c0: self.c0 - rhs.c0,
+                    c1: self.c1 - rhs.c1,
+                    c2: self.c2 - rhs.c2,
+                }
+            }
+        }
+
+        impl ::core::ops::Neg for $fp6 {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    c0: -self.c0,
+                    c1: -self.c1,
+                    c2: -self.c2,
+                }
+            }
+        }
+
+        impl ::core::ops::Mul for $fp6 {
+            type Output = Self;
+
+            /// Toom-Cook-style cubic multiplication over `$fp2`: the three
+            /// diagonal products `a_i * b_i` are shared by all coefficients,
+            /// so the product takes six `$fp2` multiplications instead of
+            /// the nine of a full 3x3 schoolbook expansion.
+            fn mul(self, rhs: Self) -> Self {
+                let a0b0 = self.c0 * rhs.c0;
+                let a1b1 = self.c1 * rhs.c1;
+                let a2b2 = self.c2 * rhs.c2;
+
+                let c0 = a0b0
+                    + ((self.c1 + self.c2) * (rhs.c1 + rhs.c2) - a1b1 - a2b2).mul_by_nonresidue_outer();
+                let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - a0b0 - a1b1
+                    + a2b2.mul_by_nonresidue_outer();
+                let c2 = (self.c0 + self.c2) * (rhs.c0 + rhs.c2) - a0b0 - a2b2 + a1b1;
+
+                Self { c0, c1, c2 }
+            }
+        }
+
+        impl ::core::iter::Sum for $fp6 {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, ::core::ops::Add::add)
+            }
+        }
+
+        impl ::core::iter::Product for $fp6 {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, ::core::ops::Mul::mul)
+            }
+        }
+
+        $crate::impl_field_ext_ops!($fp6);
+
+        impl $fp2 {
+            /// Applies the `$fp6` non-residue multiplication to a bare
+            /// `$fp2` value; a small helper shared by [`$fp6`]'s `mul` and
+            /// `invert` so they don't need to wrap/unwrap a full `$fp6`.
+            fn mul_by_nonresidue_outer(self) -> $fp2 {
+                $mul_by_nonresidue(self)
+            }
+        }
+    };
+}
+
+/// Implement tests for an `$fp6` type generated by [`impl_field_ext6!`].
+#[macro_export]
+macro_rules! impl_field_ext6_tests {
+    ($fp6:tt, $fp2:tt) => {
+        #[test]
+        fn fp6_invert_round_trips() {
+            let one = $fp2::ONE;
+            let two = one + one;
+            let elem = $fp6 {
+                c0: two,
+                c1: one,
+                c2: two + one,
+            };
+
+            let inv = elem.invert().unwrap();
+            assert_eq!(elem * inv, $fp6::ONE);
+        }
+
+        #[test]
+        fn fp6_invert_of_zero_is_none() {
+            assert!(bool::from($fp6::ZERO.invert().is_none()));
+        }
+
+        #[test]
+        fn fp6_mul_by_nonresidue_matches_mul_by_v() {
+            let one = $fp2::ONE;
+            let elem = $fp6 {
+                c0: one,
+                c1: one + one,
+                c2: one,
+            };
+            let v = $fp6 {
+                c0: $fp2::ZERO,
+                c1: one,
+                c2: $fp2::ZERO,
+            };
+            assert_eq!(elem.mul_by_nonresidue(), elem * v);
+        }
+
+        #[test]
+        fn fp6_sum_and_product() {
+            let one = $fp6::ONE;
+            let two = one + one;
+
+            let sum: $fp6 = [one, two].into_iter().sum();
+            assert_eq!(sum, one + two);
+
+            let product: $fp6 = [one, two].into_iter().product();
+            assert_eq!(product, one * two);
+        }
+    };
+}
+
+/// Implements `$fp12 = $fp6[w] / (w^2 - v)`, the quadratic extension of the
+/// sextic extension field `$fp6` (built with [`impl_field_ext6!`]).
+#[macro_export]
+macro_rules! impl_field_ext12 {
+    ($fp12:ident, $fp6:ty, $fp2:ty) => {
+        /// An element of the dodecic extension field
+        #[doc = concat!("`", stringify!($fp12), " = ", stringify!($fp6), "[w] / (w^2 - v)`.")]
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $fp12 {
+            /// Coefficient of `1`.
+            pub c0: $fp6,
+            /// Coefficient of `w`.
+            pub c1: $fp6,
+        }
+
+        impl $fp12 {
+            /// Zero element.
+            pub const ZERO: Self = Self {
+                c0: <$fp6>::ZERO,
+                c1: <$fp6>::ZERO,
+            };
+
+            /// Multiplicative identity.
+            pub const ONE: Self = Self {
+                c0: <$fp6>::ONE,
+                c1: <$fp6>::ZERO,
+            };
+
+            /// Sparse multiplication by an element of the form
+            /// `(c0 + c1 v) + (c4 v) w`, i.e. whose only nonzero `$fp2`
+            /// coefficients are in slots 0, 1 and 4: the layout of the
+            /// line evaluations produced by the Miller loop for some twists.
+            #[must_use]
+            pub fn mul_by_014(&self, c0: $fp2, c1: $fp2, c4: $fp2) -> Self {
+                let aa = self.c0.mul_by_01(c0, c1);
+                let bb = self.c1.mul_by_1(c4);
+                let o = c1 + c4;
+
+                let c1_out = (self.c1 + self.c0).mul_by_01(c0, o) - aa - bb;
+                let c0_out = bb.mul_by_nonresidue() + aa;
+
+                Self { c0: c0_out, c1: c1_out }
+            }
+
+            /// Sparse multiplication by an element of the form
+            /// `c0 + (c3 + c4 v) w`, i.e. whose only nonzero `$fp2`
+            /// coefficients are in slots 0, 3 and 4: the line-evaluation
+            /// layout used by the other twist type.
+            #[must_use]
+            pub fn mul_by_034(&self, c0: $fp2, c3: $fp2, c4: $fp2) -> Self {
+                let aa = self.c0.mul_by_0(c0);
+                let bb = self.c1.mul_by_01(c3, c4);
+                let o = c0 + c3;
+
+                let c1_out = (self.c1 + self.c0).mul_by_01(o, c4) - aa - bb;
+                let c0_out = bb.mul_by_nonresidue() + aa;
+
+                Self { c0: c0_out, c1: c1_out }
+            }
+
+            /// Returns the multiplicative inverse of this element, if it is
+            /// nonzero: `(c0 - c1*w) / (c0^2 - v*c1^2)`.
+            pub fn invert(&self) -> CtOption<Self> {
+                let t = (self.c0 * self.c0 - self.c1.mul_by_nonresidue() * self.c1).invert();
+
+                t.map(|t| Self {
+                    c0: self.c0 * t,
+                    c1: -(self.c1 * t),
+                })
+            }
+
+            /// The `p^6 - 1` part of the easy part of the final
+            /// exponentiation, used to map a Miller-loop output into the
+            /// cyclotomic subgroup: `f^(p^6 - 1) = conjugate(f) * f^-1`.
+            ///
+            /// This crate has no Frobenius coefficient tables for `$fp12`,
+            /// so the remaining `(p^2 + 1)` factor of the full easy part
+            /// (`f^((p^6 - 1)(p^2 + 1))`) is left to curve-specific code
+            /// that has those coefficients.
+            #[must_use]
+            pub fn frobenius_easy_part(&self) -> Self {
+                let conjugate = Self {
+                    c0: self.c0,
+                    c1: -self.c1,
+                };
+                conjugate * self.invert().unwrap_or(Self::ONE)
+            }
+        }
+
+        impl From<$fp6> for $fp12 {
+            fn from(c0: $fp6) -> Self {
+                Self { c0, c1: <$fp6>::ZERO }
+            }
+        }
+
+        impl ConditionallySelectable for $fp12 {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self {
+                    c0: <$fp6>::conditional_select(&a.c0, &b.c0, choice),
+                    c1: <$fp6>::conditional_select(&a.c1, &b.c1, choice),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $fp12 {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+            }
+        }
+
+        impl Eq for $fp12 {}
+        impl PartialEq for $fp12 {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
+        impl $crate::elliptic_curve::ff::Field for $fp12 {
+            const ZERO: Self = Self::ZERO;
+            const ONE: Self = Self::ONE;
+
+            fn try_from_rng<R: $crate::elliptic_curve::rand_core::TryRngCore + ?Sized>(
+                rng: &mut R,
+            ) -> core::result::Result<Self, R::Error> {
+                use $crate::elliptic_curve::ff::Field;
+
+                Ok(Self {
+                    c0: <$fp6>::try_from_rng(rng)?,
+                    c1: <$fp6>::try_from_rng(rng)?,
+                })
+            }
+
+            fn is_zero(&self) -> Choice {
+                Self::ZERO.ct_eq(self)
+            }
+
+            #[must_use]
+            fn square(&self) -> Self {
+                *self * *self
+            }
+
+            #[must_use]
+            fn double(&self) -> Self {
+                *self + *self
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                self.invert()
+            }
+
+            fn sqrt(&self) -> CtOption<Self> {
+                // No general square-root algorithm is implemented for
+                // `$fp12`; it isn't used as a base field for hash-to-curve
+                // or other contexts that need one. Zero is still its own
+                // (trivial) square root, but calling this on a nonzero
+                // value is a bug in the caller, not a legitimate "not a
+                // square" answer — catch that loudly in debug rather than
+                // silently reporting every nonzero input as a non-square.
+                let is_zero = self.ct_eq(&Self::ZERO);
+                debug_assert!(
+                    bool::from(is_zero),
+                    "{}::sqrt has no implementation for nonzero inputs",
+                    stringify!($fp12),
+                );
+                CtOption::new(Self::ZERO, is_zero)
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                let is_num_zero = num.ct_eq(&Self::ZERO);
+                let is_div_zero = div.ct_eq(&Self::ZERO);
+                debug_assert!(
+                    bool::from(is_num_zero) || bool::from(is_div_zero),
+                    "{}::sqrt_ratio has no implementation for nonzero numerators",
+                    stringify!($fp12),
+                );
+                (is_num_zero & !is_div_zero, Self::ZERO)
+            }
+        }
+
+        impl ::core::ops::Add for $fp12 {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    c0: self.c0 + rhs.c0,
+                    c1: self.c1 + rhs.c1,
+                }
+            }
+        }
+
+        impl ::core::ops::Sub for $fp12 {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    c0: self.c0 - rhs.c0,
+                    c1: self.c1 - rhs.c1,
+                }
+            }
+        }
+
+        impl ::core::ops::Neg for $fp12 {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    c0: -self.c0,
+                    c1: -self.c1,
+                }
+            }
+        }
+
+        impl ::core::ops::Mul for $fp12 {
+            type Output = Self;
+
+            /// Karatsuba multiplication over `$fp6`:
+            /// `(a0+a1 w)(b0+b1 w) = (a0 b0 + v a1 b1) + (a0 b1 + a1 b0) w`.
+            fn mul(self, rhs: Self) -> Self {
+                let a0b0 = self.c0 * rhs.c0;
+                let a1b1 = self.c1 * rhs.c1;
+
+                Self {
+                    c0: a0b0 + a1b1.mul_by_nonresidue(),
+                    c1: (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - a0b0 - a1b1,
+                }
+            }
+        }
+
+        impl ::core::iter::Sum for $fp12 {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, ::core::ops::Add::add)
+            }
+        }
+
+        impl ::core::iter::Product for $fp12 {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, ::core::ops::Mul::mul)
+            }
+        }
+
+        $crate::impl_field_ext_ops!($fp12);
+
+        impl $fp6 {
+            /// Sparse multiply by `c0 + c1*v` (`c2` implicitly zero).
+            fn mul_by_01(self, c0: $fp2, c1: $fp2) -> $fp6 {
+                Self {
+                    c0: self.c0,
+                    c1: self.c1,
+                    c2: self.c2,
+                } * Self { c0, c1, c2: <$fp2>::ZERO }
+            }
+
+            /// Sparse multiply by `c0` alone (`c1`, `c2` implicitly zero).
+            fn mul_by_0(self, c0: $fp2) -> $fp6 {
+                self * Self::from(c0)
+            }
+
+            /// Sparse multiply by `c1*v` alone (`c0`, `c2` implicitly zero).
+            fn mul_by_1(self, c1: $fp2) -> $fp6 {
+                self * Self {
+                    c0: <$fp2>::ZERO,
+                    c1,
+                    c2: <$fp2>::ZERO,
+                }
+            }
+        }
+    };
+}
+
+/// Implement tests for an `$fp12` type generated by [`impl_field_ext12!`].
+#[macro_export]
+macro_rules! impl_field_ext12_tests {
+    ($fp12:tt, $fp6:tt, $fp2:tt) => {
+        #[test]
+        fn fp12_invert_round_trips() {
+            let one = $fp6::ONE;
+            let two = one + one;
+            let elem = $fp12 { c0: two, c1: one };
+
+            let inv = elem.invert().unwrap();
+            assert_eq!(elem * inv, $fp12::ONE);
+        }
+
+        #[test]
+        fn fp12_invert_of_zero_is_none() {
+            assert!(bool::from($fp12::ZERO.invert().is_none()));
+        }
+
+        #[test]
+        fn fp12_mul_by_014_matches_general_mul() {
+            let one = $fp2::ONE;
+            let c0 = one + one;
+            let c1 = one;
+            let c4 = one + one + one;
+
+            let elem = $fp12 {
+                c0: $fp6 {
+                    c0: one,
+                    c1: one + one,
+                    c2: $fp2::ZERO,
+                },
+                c1: $fp6 {
+                    c0: one,
+                    c1: $fp2::ZERO,
+                    c2: one,
+                },
+            };
+
+            let sparse = $fp12 {
+                c0: $fp6 {
+                    c0,
+                    c1,
+                    c2: $fp2::ZERO,
+                },
+                c1: $fp6 {
+                    c0: $fp2::ZERO,
+                    c1: c4,
+                    c2: $fp2::ZERO,
+                },
+            };
+
+            assert_eq!(elem.mul_by_014(c0, c1, c4), elem * sparse);
+        }
+
+        #[test]
+        fn fp12_mul_by_034_matches_general_mul() {
+            let one = $fp2::ONE;
+            let c0 = one + one;
+            let c3 = one;
+            let c4 = one + one + one;
+
+            let elem = $fp12 {
+                c0: $fp6 {
+                    c0: one,
+                    c1: one + one,
+                    c2: $fp2::ZERO,
+                },
+                c1: $fp6 {
+                    c0: one,
+                    c1: $fp2::ZERO,
+                    c2: one,
+                },
+            };
+
+            let sparse = $fp12 {
+                c0: $fp6 {
+                    c0,
+                    c1: $fp2::ZERO,
+                    c2: $fp2::ZERO,
+                },
+                c1: $fp6 {
+                    c0: c3,
+                    c1: c4,
+                    c2: $fp2::ZERO,
+                },
+            };
+
+            assert_eq!(elem.mul_by_034(c0, c3, c4), elem * sparse);
+        }
+
+        #[test]
+        fn fp12_sum_and_product() {
+            let one = $fp12::ONE;
+            let two = one + one;
+
+            let sum: $fp12 = [one, two].into_iter().sum();
+            assert_eq!(sum, one + two);
+
+            let product: $fp12 = [one, two].into_iter().product();
+            assert_eq!(product, one * two);
+        }
+    };
+}